@@ -1,9 +1,10 @@
 use std::io::Result;
 use std::thread::sleep;
+use std::time::Duration;
 
 use evdev::{Device, EventType, InputEvent, SoundCode};
 
-use crate::Note;
+use crate::{chord, modulation, Chord, Melody, MelodyEvent, Modulation, Note};
 
 /// Allows to beep the PC speaker.
 pub trait Beep {
@@ -45,16 +46,12 @@ pub trait Beep {
     /// ```
     fn note(&mut self, note: &Note) -> Result<()> {
         if note.repeats() > 0 {
-            self.beep(note.frequency())?;
-            sleep(note.length());
-            self.beep(0)?;
+            sound(self, note)?;
         }
 
         for _ in 1..note.repeats() {
             sleep(note.delay());
-            self.beep(note.frequency())?;
-            sleep(note.length());
-            self.beep(0)?;
+            sound(self, note)?;
         }
 
         Ok(())
@@ -124,6 +121,54 @@ pub trait Beep {
 
         Ok(())
     }
+
+    /// Play the given chord on the PC speaker by round-robining between its
+    /// frequencies for `length`, approximating polyphony on hardware that
+    /// can only sound one frequency at a time.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error`] if beeping the PC speaker fails.
+    fn chord(&mut self, chord: &Chord, length: Duration) -> Result<()> {
+        let frequencies = chord.as_ref();
+
+        if frequencies.is_empty() || length.is_zero() {
+            return Ok(());
+        }
+
+        let slice = chord::SLICE.min(length);
+        let mut elapsed = Duration::ZERO;
+        let mut step = 0;
+
+        while elapsed < length {
+            let step_len = slice.min(length - elapsed);
+            self.beep(frequencies[step % frequencies.len()])?;
+            sleep(step_len);
+            elapsed += step_len;
+            step += 1;
+        }
+
+        self.beep(0)
+    }
+
+    /// Play the given melody, dispatching each event to [`Beep::note`] or [`Beep::chord`].
+    ///
+    /// Unlike [`Beep::play`], this understands multi-voice [`Melody`] data:
+    /// chord events are arpeggiated instead of dropping all but one voice.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error`] if beeping the PC speaker fails.
+    fn play_melody(&mut self, melody: &Melody) -> Result<()> {
+        for event in melody.events() {
+            match event {
+                MelodyEvent::Note(note) => self.note(note)?,
+                MelodyEvent::Chord(chord, length) => self.chord(chord, *length)?,
+            }
+        }
+
+        Ok(())
+    }
 }
 
 impl Beep for Device {
@@ -135,3 +180,37 @@ impl Beep for Device {
         )])
     }
 }
+
+/// Plays `note`'s length, realizing its modulation envelope if it has one.
+fn sound<T>(device: &mut T, note: &Note) -> Result<()>
+where
+    T: Beep + ?Sized,
+{
+    match note.modulation() {
+        Some(modulation) => modulated_sound(device, note, modulation),
+        None => {
+            device.beep(note.frequency())?;
+            sleep(note.length());
+            device.beep(0)
+        }
+    }
+}
+
+/// Subdivides `note`'s length into short steps, sending a `SND_TONE` event
+/// for each one so the modulation envelope is audible.
+fn modulated_sound<T>(device: &mut T, note: &Note, modulation: Modulation) -> Result<()>
+where
+    T: Beep + ?Sized,
+{
+    let length = note.length();
+    let mut elapsed = Duration::ZERO;
+
+    while elapsed < length {
+        let step = modulation::STEP.min(length - elapsed);
+        device.beep(modulation.frequency_at(note.frequency(), elapsed, length))?;
+        sleep(step);
+        elapsed += step;
+    }
+
+    device.beep(0)
+}
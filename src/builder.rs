@@ -0,0 +1,101 @@
+use std::time::Duration;
+
+use crate::{Melody, Note};
+
+/// A rhythmic note value, relative to a quarter note.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum NoteValue {
+    /// A whole note (four beats).
+    Whole,
+    /// A half note (two beats).
+    Half,
+    /// A quarter note (one beat).
+    Quarter,
+    /// An eighth note (half a beat).
+    Eighth,
+    /// A sixteenth note (a quarter of a beat).
+    Sixteenth,
+    /// A thirty-second note (an eighth of a beat).
+    ThirtySecond,
+}
+
+impl NoteValue {
+    /// Returns the number of quarter-note beats this value spans, lengthened
+    /// by half if `dotted` is `true`.
+    #[must_use]
+    pub const fn beats(self, dotted: bool) -> f64 {
+        let beats = match self {
+            Self::Whole => 4.0,
+            Self::Half => 2.0,
+            Self::Quarter => 1.0,
+            Self::Eighth => 0.5,
+            Self::Sixteenth => 0.25,
+            Self::ThirtySecond => 0.125,
+        };
+
+        if dotted {
+            beats * 1.5
+        } else {
+            beats
+        }
+    }
+}
+
+/// Builds a [`Melody`] from rhythmic note values at a fixed tempo, so tunes
+/// can be transcribed the way sheet music expresses them instead of as raw
+/// `(frequency, length)` pairs.
+#[derive(Clone, Debug)]
+pub struct MelodyBuilder {
+    bpm: f64,
+    notes: Vec<Note>,
+}
+
+impl MelodyBuilder {
+    /// Creates a new builder at the given tempo in beats (quarter notes) per minute.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bpm` is not finite or not positive, since a zero, negative
+    /// or non-finite tempo leaves every note's length undefined.
+    #[must_use]
+    pub fn new(bpm: f64) -> Self {
+        assert!(bpm.is_finite() && bpm > 0.0, "bpm must be finite and positive");
+
+        Self {
+            bpm,
+            notes: Vec::new(),
+        }
+    }
+
+    /// Appends a note at `frequency` with the given rhythmic value.
+    #[must_use]
+    pub fn note(mut self, frequency: u16, value: NoteValue, dotted: bool) -> Self {
+        self.notes.push(Note::from((frequency, self.duration(value, dotted))));
+        self
+    }
+
+    /// Appends a note from a MIDI key number with the given rhythmic value.
+    #[must_use]
+    pub fn midi_note(mut self, key: u8, value: NoteValue, dotted: bool) -> Self {
+        let length = self.duration(value, dotted);
+        self.notes.push(Note::from_midi(key, length));
+        self
+    }
+
+    /// Appends a rest (silence) with the given rhythmic value.
+    #[must_use]
+    pub fn rest(mut self, value: NoteValue, dotted: bool) -> Self {
+        self.notes.push(Note::from((0, self.duration(value, dotted))));
+        self
+    }
+
+    /// Finishes building and returns the resulting [`Melody`].
+    #[must_use]
+    pub fn build(self) -> Melody {
+        Melody::from(self.notes)
+    }
+
+    fn duration(&self, value: NoteValue, dotted: bool) -> Duration {
+        Duration::from_secs_f64(60.0 / self.bpm * value.beats(dotted))
+    }
+}
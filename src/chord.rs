@@ -0,0 +1,40 @@
+use std::time::Duration;
+
+/// Duration of a single slice when arpeggiating a [`Chord`], chosen in the
+/// 15-20 ms range: fast enough to read as a held chord, slow enough for the
+/// speaker to re-latch audibly.
+pub(crate) const SLICE: Duration = Duration::from_millis(18);
+
+/// A set of frequencies meant to sound simultaneously.
+///
+/// The PC speaker can only sound one frequency at a time, so playing a chord
+/// means rapidly round-robining between its frequencies instead of holding
+/// them all at once; see [`crate::Beep::chord`] and [`crate::Pcspkr::chord`].
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Chord(Box<[u16]>);
+
+impl Chord {
+    #[must_use]
+    pub fn new(frequencies: Box<[u16]>) -> Self {
+        Self(frequencies)
+    }
+}
+
+impl AsRef<[u16]> for Chord {
+    fn as_ref(&self) -> &[u16] {
+        &self.0
+    }
+}
+
+impl From<Vec<u16>> for Chord {
+    fn from(frequencies: Vec<u16>) -> Self {
+        Self::new(frequencies.into_boxed_slice())
+    }
+}
+
+impl From<&[u16]> for Chord {
+    fn from(frequencies: &[u16]) -> Self {
+        Self::new(frequencies.into())
+    }
+}
@@ -0,0 +1,37 @@
+use std::fmt;
+
+/// Errors that can occur while parsing a [`crate::Melody`] from an external format.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ParseError {
+    /// The input ended before a complete chunk or event could be read.
+    UnexpectedEof,
+    /// A chunk header did not carry the expected magic bytes.
+    InvalidChunkHeader,
+    /// A chunk declared a length that does not fit inside the remaining input.
+    TruncatedChunk,
+    /// An event could not be decoded.
+    InvalidEvent,
+    /// A note name was not valid scientific pitch notation (e.g. `"A4"`, `"C#5"`, `"Bb3"`),
+    /// or an RTTTL string lacked a section or held a number that could not be parsed.
+    InvalidNoteName,
+    /// A note or header duration value was zero, making its beat length undefined.
+    InvalidDuration,
+    /// A tempo value was zero, negative, or non-finite.
+    InvalidTempo,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnexpectedEof => write!(f, "unexpected end of input"),
+            Self::InvalidChunkHeader => write!(f, "invalid or missing chunk header"),
+            Self::TruncatedChunk => write!(f, "chunk length exceeds remaining input"),
+            Self::InvalidEvent => write!(f, "could not decode event"),
+            Self::InvalidNoteName => write!(f, "invalid note name"),
+            Self::InvalidDuration => write!(f, "invalid duration: must be non-zero"),
+            Self::InvalidTempo => write!(f, "invalid tempo: must be finite and positive"),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
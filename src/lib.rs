@@ -3,10 +3,26 @@
 use std::time::Duration;
 
 pub use beep::Beep;
+pub use builder::{MelodyBuilder, NoteValue};
+pub use chord::Chord;
+pub use error::ParseError;
+pub use melody::{Melody, MelodyEvent};
+pub use modulation::Modulation;
 pub use note::Note;
+pub use pcspkr::Pcspkr;
+pub use player::{Control, Event, EventReceiver, Player, PlayerHandle};
 
 mod beep;
+mod builder;
+mod chord;
+mod error;
+mod melody;
+mod modulation;
 mod note;
+mod pcspkr;
+mod player;
+mod rtttl;
+mod smf;
 
 /// Default duration of a note.
 pub const DEFAULT_DELAY: Duration = Duration::from_millis(100);
@@ -1,37 +1,201 @@
-use crate::Note;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::time::Duration;
 
-/// A sequence of notes
-#[derive(Clone, Debug, Eq, PartialEq)]
+use crate::note::frequency_from_midi;
+use crate::rtttl;
+use crate::smf::{self, TrackEvent};
+use crate::{Chord, Note, ParseError, DEFAULT_REPEATS};
+
+/// A single element of a [`Melody`]: either a monophonic [`Note`] or a
+/// [`Chord`] held for a fixed length.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum MelodyEvent {
+    /// A single note.
+    Note(Note),
+    /// A chord, arpeggiated over `length`.
+    Chord(Chord, Duration),
+}
+
+/// A sequence of notes and chords.
+#[derive(Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub struct Melody(Box<[Note]>);
+pub struct Melody(Box<[MelodyEvent]>);
 
 impl Melody {
     #[must_use]
-    pub fn new(notes: Box<[Note]>) -> Self {
-        Self(notes)
+    pub fn new(events: Box<[MelodyEvent]>) -> Self {
+        Self(events)
+    }
+
+    /// Creates a melody from a sequence of [`MelodyEvent`]s.
+    ///
+    /// This is a named constructor rather than a `From<Vec<MelodyEvent>>`
+    /// impl because `Melody` also implements `From<Vec<Note>>`, and the two
+    /// would make `vec![...].into(): Melody` ambiguous whenever the element
+    /// type has to be inferred.
+    #[must_use]
+    pub fn from_events(events: Vec<MelodyEvent>) -> Self {
+        Self::new(events.into_boxed_slice())
     }
-}
 
-impl AsRef<[Note]> for Melody {
-    fn as_ref(&self) -> &[Note] {
+    /// Returns the events that make up this melody.
+    #[must_use]
+    pub fn events(&self) -> &[MelodyEvent] {
         &self.0
     }
+
+    /// Parses a Standard MIDI File into a [`Melody`].
+    ///
+    /// The PC speaker can only sound one pitch at a time, so overlapping
+    /// notes are degraded gracefully rather than simply dropped: a single
+    /// held key becomes a [`MelodyEvent::Note`] and a rest (frequency `0`)
+    /// fills any gap where no key is held, but two or more simultaneously
+    /// held keys become a [`MelodyEvent::Chord`] that [`crate::Beep::chord`]
+    /// arpeggiates. Tempo meta events are honored as they occur, so tempo
+    /// changes mid-file are reflected in the resulting event lengths.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ParseError`] if `bytes` is not a well-formed SMF.
+    pub fn from_smf(bytes: &[u8]) -> Result<Self, ParseError> {
+        let (header, events) = smf::parse(bytes)?;
+        let division = u64::from(header.division.max(1));
+        let mut melody_events = Vec::new();
+        let mut held: Vec<u8> = Vec::new();
+        let mut usec_per_quarter: u64 = 500_000;
+        let mut last_tick: u64 = 0;
+
+        for timed in events {
+            let delta_ticks = timed.tick - last_tick;
+
+            if delta_ticks > 0 {
+                let length = Duration::from_micros(delta_ticks * usec_per_quarter / division);
+
+                melody_events.push(match held.as_slice() {
+                    [] => MelodyEvent::Note(Note::new(0, length, DEFAULT_REPEATS, Duration::ZERO)),
+                    [key] => MelodyEvent::Note(Note::new(
+                        frequency_from_midi(*key),
+                        length,
+                        DEFAULT_REPEATS,
+                        Duration::ZERO,
+                    )),
+                    keys => MelodyEvent::Chord(
+                        keys.iter().copied().map(frequency_from_midi).collect::<Vec<_>>().into(),
+                        length,
+                    ),
+                });
+            }
+
+            last_tick = timed.tick;
+
+            match timed.event {
+                TrackEvent::NoteOn { key } => {
+                    held.retain(|&held_key| held_key != key);
+                    held.push(key);
+                }
+                TrackEvent::NoteOff { key } => held.retain(|&held_key| held_key != key),
+                TrackEvent::Tempo(tempo) => usec_per_quarter = u64::from(tempo),
+            }
+        }
+
+        Ok(Self::from_events(melody_events))
+    }
+
+    /// Parses an RTTTL (ring tone text transfer language) string into a [`Melody`].
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ParseError`] if `input` is not valid RTTTL.
+    pub fn from_rtttl(input: &str) -> Result<Self, ParseError> {
+        Ok(Self::from_events(rtttl::parse(input)?))
+    }
+
+    /// Renders this melody as an RTTTL string with the given ring tone `name`
+    /// and `bpm` tempo.
+    #[must_use]
+    pub fn to_rtttl(&self, name: &str, bpm: f64) -> String {
+        rtttl::format(&self.0, name, bpm)
+    }
+
+    /// Loads a melody from a file, inferring the format from its extension:
+    /// `.rtttl`/`.txt` for RTTTL, or `.json`/`.ron` when built with the
+    /// `serde` feature.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`io::Error`] if the file cannot be read or does not parse.
+    pub fn load_from_path<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        let contents = fs::read_to_string(&path)?;
+
+        match extension(path.as_ref()) {
+            Some("rtttl" | "txt") => {
+                Self::from_rtttl(&contents).map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))
+            }
+            #[cfg(feature = "serde")]
+            Some("json") => {
+                serde_json::from_str(&contents).map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))
+            }
+            #[cfg(feature = "serde")]
+            Some("ron") => ron::from_str(&contents).map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error)),
+            _ => Err(unsupported_extension()),
+        }
+    }
+
+    /// Saves this melody to a file, choosing the format from its extension
+    /// (see [`Melody::load_from_path`]). RTTTL files are named after the
+    /// file stem and written at the classic RTTTL default tempo.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`io::Error`] if the file cannot be written.
+    pub fn save_to_path<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        let contents = match extension(path.as_ref()) {
+            Some("rtttl" | "txt") => {
+                let name = path
+                    .as_ref()
+                    .file_stem()
+                    .and_then(std::ffi::OsStr::to_str)
+                    .unwrap_or("melody");
+                self.to_rtttl(name, rtttl::DEFAULT_BPM)
+            }
+            #[cfg(feature = "serde")]
+            Some("json") => {
+                serde_json::to_string_pretty(self).map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))?
+            }
+            #[cfg(feature = "serde")]
+            Some("ron") => ron::to_string(self).map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))?,
+            _ => return Err(unsupported_extension()),
+        };
+
+        fs::write(path, contents)
+    }
+}
+
+fn extension(path: &Path) -> Option<&str> {
+    path.extension().and_then(std::ffi::OsStr::to_str)
+}
+
+fn unsupported_extension() -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidInput, "unsupported melody file extension")
 }
 
 impl Default for Melody {
     fn default() -> Self {
-        Self::new(Box::new([Note::default()]))
+        Self::new(Box::new([MelodyEvent::Note(Note::default())]))
     }
 }
 
 impl From<Vec<Note>> for Melody {
     fn from(notes: Vec<Note>) -> Self {
-        Self::new(notes.into_boxed_slice())
+        Self::new(notes.into_iter().map(MelodyEvent::Note).collect())
     }
 }
 
 impl From<&[Note]> for Melody {
     fn from(notes: &[Note]) -> Self {
-        Self::new(notes.into())
+        Self::from(notes.to_vec())
     }
 }
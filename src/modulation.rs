@@ -0,0 +1,109 @@
+use std::time::Duration;
+
+/// A duration for which a modulated frequency is held before being updated,
+/// chosen so the PC speaker audibly re-latches at each step.
+pub(crate) const STEP: Duration = Duration::from_millis(8);
+
+/// A frequency modulation envelope applied over the length of a [`crate::Note`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Modulation {
+    /// A sinusoidal wobble around the base frequency.
+    Vibrato {
+        /// Peak deviation from the base frequency, in cents.
+        depth_cents: f64,
+        /// Oscillations per second.
+        rate_hz: f64,
+    },
+    /// A linear sweep from the note's base frequency to `target_hz`.
+    Glide {
+        /// Target frequency in Hertz, reached at the end of the note.
+        target_hz: u16,
+    },
+    /// A constant offset from the base frequency, in cents.
+    Bend {
+        /// Offset from the base frequency, in cents.
+        cents: f64,
+    },
+}
+
+impl Modulation {
+    /// Computes the modulated frequency at `elapsed` into a note of the
+    /// given `base` frequency and total `length`.
+    #[must_use]
+    pub(crate) fn frequency_at(self, base: u16, elapsed: Duration, length: Duration) -> u16 {
+        match self {
+            Self::Vibrato { depth_cents, rate_hz } => {
+                let phase = elapsed.as_secs_f64() * rate_hz * std::f64::consts::TAU;
+                cents_offset(base, depth_cents * phase.sin())
+            }
+            Self::Glide { target_hz } => {
+                let progress = if length.is_zero() {
+                    1.0
+                } else {
+                    (elapsed.as_secs_f64() / length.as_secs_f64()).min(1.0)
+                };
+                let base = f64::from(base);
+                let target = f64::from(target_hz);
+                round_to_u16(base + (target - base) * progress)
+            }
+            Self::Bend { cents } => cents_offset(base, cents),
+        }
+    }
+}
+
+/// Offsets `base` by `cents` hundredths of a semitone.
+fn cents_offset(base: u16, cents: f64) -> u16 {
+    round_to_u16(f64::from(base) * 2f64.powf(cents / 1200.0))
+}
+
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn round_to_u16(hertz: f64) -> u16 {
+    hertz.round() as u16
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::Modulation;
+
+    #[test]
+    fn bend_offsets_base_frequency() {
+        let modulation = Modulation::Bend { cents: 1200.0 };
+        // +1200 cents is exactly one octave up.
+        assert_eq!(modulation.frequency_at(440, Duration::ZERO, Duration::from_secs(1)), 880);
+    }
+
+    #[test]
+    fn bend_of_zero_cents_is_a_no_op() {
+        let modulation = Modulation::Bend { cents: 0.0 };
+        assert_eq!(modulation.frequency_at(440, Duration::ZERO, Duration::from_secs(1)), 440);
+    }
+
+    #[test]
+    fn vibrato_starts_at_base_frequency() {
+        let modulation = Modulation::Vibrato {
+            depth_cents: 50.0,
+            rate_hz: 5.0,
+        };
+        // sin(0) == 0, so no offset has been applied yet.
+        assert_eq!(modulation.frequency_at(440, Duration::ZERO, Duration::from_secs(1)), 440);
+    }
+
+    #[test]
+    fn glide_starts_at_base_and_ends_at_target() {
+        let modulation = Modulation::Glide { target_hz: 880 };
+        let length = Duration::from_secs(1);
+
+        assert_eq!(modulation.frequency_at(440, Duration::ZERO, length), 440);
+        assert_eq!(modulation.frequency_at(440, length, length), 880);
+        assert_eq!(modulation.frequency_at(440, length / 2, length), 660);
+    }
+
+    #[test]
+    fn glide_with_zero_length_jumps_straight_to_target() {
+        let modulation = Modulation::Glide { target_hz: 880 };
+        assert_eq!(modulation.frequency_at(440, Duration::ZERO, Duration::ZERO), 880);
+    }
+}
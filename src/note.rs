@@ -1,16 +1,17 @@
 use std::time::Duration;
 
-use crate::{DEFAULT_DELAY, DEFAULT_FREQ, DEFAULT_LEN, DEFAULT_REPEATS};
+use crate::{Modulation, ParseError, DEFAULT_DELAY, DEFAULT_FREQ, DEFAULT_LEN, DEFAULT_REPEATS};
 
 /// A note of a certain frequency and duration
 /// that may be repeated with a delay.
-#[derive(Clone, Debug, Eq, PartialEq)]
+#[derive(Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Note {
     frequency: u16,
     length: Duration,
     repeats: u16,
     delay: Duration,
+    modulation: Option<Modulation>,
 }
 
 impl Note {
@@ -22,6 +23,7 @@ impl Note {
             length,
             repeats,
             delay,
+            modulation: None,
         }
     }
 
@@ -48,6 +50,36 @@ impl Note {
     pub const fn delay(&self) -> Duration {
         self.delay
     }
+
+    /// Returns the frequency modulation envelope, if any.
+    #[must_use]
+    pub const fn modulation(&self) -> Option<Modulation> {
+        self.modulation
+    }
+
+    /// Returns this note with the given frequency modulation envelope applied.
+    #[must_use]
+    pub const fn with_modulation(mut self, modulation: Modulation) -> Self {
+        self.modulation = Some(modulation);
+        self
+    }
+
+    /// Creates a note from a MIDI key number, using equal temperament
+    /// (`440 * 2^((key - 69) / 12)`) to derive its frequency.
+    #[must_use]
+    pub fn from_midi(key: u8, length: Duration) -> Self {
+        Self::new(frequency_from_midi(key), length, DEFAULT_REPEATS, DEFAULT_DELAY)
+    }
+
+    /// Creates a note from a name in scientific pitch notation, e.g.
+    /// `"A4"`, `"C#5"` or `"Bb3"`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ParseError`] if `name` is not a valid note name.
+    pub fn from_name(name: &str, length: Duration) -> Result<Self, ParseError> {
+        midi_from_name(name).map(|key| Self::from_midi(key, length))
+    }
 }
 
 impl Default for Note {
@@ -69,3 +101,45 @@ impl From<(u16, Duration)> for Note {
         Self::new(frequency, length, DEFAULT_REPEATS, DEFAULT_DELAY)
     }
 }
+
+/// Converts a MIDI key number to its equal-temperament frequency in Hertz,
+/// using A4 (key 69) as 440 Hz.
+pub(crate) fn frequency_from_midi(key: u8) -> u16 {
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let hertz = 440.0 * 2f64.powf(f64::from(i32::from(key) - 69) / 12.0);
+    hertz.round() as u16
+}
+
+/// Parses a note name in scientific pitch notation (e.g. `"A4"`, `"C#5"`,
+/// `"Bb3"`) into a MIDI key number, using the convention that middle C
+/// (`"C4"`) is key 60.
+pub(crate) fn midi_from_name(name: &str) -> Result<u8, ParseError> {
+    let mut chars = name.chars();
+    let semitone = match chars.next().ok_or(ParseError::InvalidNoteName)? {
+        'C' | 'c' => 0,
+        'D' | 'd' => 2,
+        'E' | 'e' => 4,
+        'F' | 'f' => 5,
+        'G' | 'g' => 7,
+        'A' | 'a' => 9,
+        'B' | 'b' => 11,
+        _ => return Err(ParseError::InvalidNoteName),
+    };
+    let rest = chars.as_str();
+
+    let (semitone, rest) = match rest.strip_prefix('#') {
+        Some(rest) => (semitone + 1, rest),
+        None => match rest.strip_prefix('b') {
+            Some(rest) => (semitone - 1, rest),
+            None => (semitone, rest),
+        },
+    };
+
+    let octave: i32 = rest.parse().map_err(|_| ParseError::InvalidNoteName)?;
+    let key = octave
+        .checked_add(1)
+        .and_then(|octave| octave.checked_mul(12))
+        .and_then(|base| base.checked_add(semitone))
+        .ok_or(ParseError::InvalidNoteName)?;
+    u8::try_from(key).map_err(|_| ParseError::InvalidNoteName)
+}
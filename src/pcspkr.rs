@@ -1,6 +1,7 @@
-use crate::{Melody, Note, FILE};
+use crate::{chord, modulation, Chord, Melody, MelodyEvent, Modulation, Note, DEFAULT_FILE};
 use evdev::{Device, EventType, InputEvent, SoundType};
 use std::thread::sleep;
+use std::time::Duration;
 
 pub struct Pcspkr {
     device: Device,
@@ -50,21 +51,73 @@ impl Pcspkr {
     /// Returns an [`std::io::Error`] on I/O errors
     pub fn note(&mut self, note: &Note) -> Result<(), std::io::Error> {
         if note.repeats() > 0 {
-            self.beep(note.frequency())?;
-            sleep(note.length());
-            self.beep(0)?;
+            self.sound(note)?;
         }
 
         for _ in 1..note.repeats() {
             sleep(note.delay());
-            self.beep(note.frequency())?;
-            sleep(note.length());
-            self.beep(0)?;
+            self.sound(note)?;
         }
 
         Ok(())
     }
 
+    /// Plays `note`'s length, realizing its modulation envelope if it has one.
+    fn sound(&mut self, note: &Note) -> std::io::Result<()> {
+        match note.modulation() {
+            Some(modulation) => self.modulated_sound(note, modulation),
+            None => {
+                self.beep(note.frequency())?;
+                sleep(note.length());
+                self.beep(0)
+            }
+        }
+    }
+
+    /// Subdivides `note`'s length into short steps, sending a `SND_TONE`
+    /// event for each one so the modulation envelope is audible.
+    fn modulated_sound(&mut self, note: &Note, modulation: Modulation) -> std::io::Result<()> {
+        let length = note.length();
+        let mut elapsed = Duration::ZERO;
+
+        while elapsed < length {
+            let step = modulation::STEP.min(length - elapsed);
+            self.beep(modulation.frequency_at(note.frequency(), elapsed, length))?;
+            sleep(step);
+            elapsed += step;
+        }
+
+        self.beep(0)
+    }
+
+    /// Plays a chord by round-robining between its frequencies for `length`,
+    /// approximating polyphony on hardware that can only sound one
+    /// frequency at a time.
+    ///
+    /// # Errors
+    /// Returns an [`std::io::Error`] on I/O errors
+    pub fn chord(&mut self, chord: &Chord, length: Duration) -> std::io::Result<()> {
+        let frequencies = chord.as_ref();
+
+        if frequencies.is_empty() || length.is_zero() {
+            return Ok(());
+        }
+
+        let slice = chord::SLICE.min(length);
+        let mut elapsed = Duration::ZERO;
+        let mut step = 0;
+
+        while elapsed < length {
+            let step_len = slice.min(length - elapsed);
+            self.beep(frequencies[step % frequencies.len()])?;
+            sleep(step_len);
+            elapsed += step_len;
+            step += 1;
+        }
+
+        self.beep(0)
+    }
+
     /// Plays a melody.
     ///
     /// # Examples
@@ -115,8 +168,11 @@ impl Pcspkr {
     /// # Errors
     /// Returns an [`std::io::Error`] on I/O errors
     pub fn play(&mut self, melody: &Melody) -> Result<(), std::io::Error> {
-        for note in melody.as_ref() {
-            self.note(note)?;
+        for event in melody.events() {
+            match event {
+                MelodyEvent::Note(note) => self.note(note)?,
+                MelodyEvent::Chord(chord, length) => self.chord(chord, *length)?,
+            }
         }
 
         Ok(())
@@ -125,6 +181,6 @@ impl Pcspkr {
 
 impl Default for Pcspkr {
     fn default() -> Self {
-        Self::new(Device::open(FILE).expect("failed to open device"))
+        Self::new(Device::open(DEFAULT_FILE).expect("failed to open device"))
     }
 }
@@ -0,0 +1,522 @@
+//! Non-blocking melody playback with pause/resume/stop/skip/seek control.
+
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::thread::{self, sleep, JoinHandle};
+use std::time::Duration;
+
+use crate::{chord, Beep, Chord, Melody, MelodyEvent, Note};
+
+/// A control message accepted by a running [`Player`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Control {
+    /// Suspends playback at the next control check: between notes, between
+    /// a note's repeats, or between a chord's slices.
+    Pause,
+    /// Resumes playback after a [`Control::Pause`].
+    Resume,
+    /// Stops playback and silences the speaker.
+    Stop,
+    /// Abandons the remaining repeats or chord slices of the current note and
+    /// moves on to the next one. Received after a note's last repeat, it
+    /// skips the note that would play next instead.
+    Skip,
+    /// Jumps to the note at the given index.
+    Seek(usize),
+}
+
+/// A transport event emitted by a running [`Player`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Event {
+    /// A note started playing.
+    NoteStarted {
+        /// Index of the note within the melody.
+        index: usize,
+        /// Frequency of the note in Hertz.
+        frequency: u16,
+    },
+    /// The current note finished playing (or was skipped/seeked past).
+    NoteFinished,
+    /// The whole melody finished playing, or playback was stopped.
+    MelodyFinished,
+}
+
+/// The receiving end of a [`Player`]'s transport event stream.
+pub struct EventReceiver(Receiver<Event>);
+
+impl EventReceiver {
+    /// Returns the next event, blocking until one is available.
+    ///
+    /// Returns `None` once the player has shut down and no more events will follow.
+    pub fn recv(&self) -> Option<Event> {
+        self.0.recv().ok()
+    }
+
+    /// Returns the next event without blocking, if one is already queued.
+    pub fn try_recv(&self) -> Option<Event> {
+        self.0.try_recv().ok()
+    }
+
+    /// Returns an iterator over events, blocking between items until the player shuts down.
+    pub fn iter(&self) -> impl Iterator<Item = Event> + '_ {
+        self.0.iter()
+    }
+}
+
+/// A handle to control a [`Player`] running on a background thread.
+///
+/// Dropping the handle stops playback and waits for the background thread to exit.
+pub struct PlayerHandle {
+    control: Sender<Control>,
+    worker: Option<JoinHandle<()>>,
+}
+
+impl PlayerHandle {
+    fn send(&self, control: Control) {
+        let _ = self.control.send(control);
+    }
+
+    /// Suspends playback at the next control check; see [`Control::Pause`].
+    pub fn pause(&self) {
+        self.send(Control::Pause);
+    }
+
+    /// Resumes playback after a [`PlayerHandle::pause`].
+    pub fn resume(&self) {
+        self.send(Control::Resume);
+    }
+
+    /// Stops playback and silences the speaker.
+    pub fn stop(&self) {
+        self.send(Control::Stop);
+    }
+
+    /// Moves on to the next note; see [`Control::Skip`].
+    pub fn skip(&self) {
+        self.send(Control::Skip);
+    }
+
+    /// Jumps to the note at the given index.
+    pub fn seek(&self, index: usize) {
+        self.send(Control::Seek(index));
+    }
+}
+
+impl Drop for PlayerHandle {
+    fn drop(&mut self) {
+        self.send(Control::Stop);
+
+        if let Some(worker) = self.worker.take() {
+            let _ = worker.join();
+        }
+    }
+}
+
+/// Plays a [`Melody`] on a background thread under remote control.
+pub struct Player;
+
+impl Player {
+    /// Spawns `melody` playing on `device` on a background thread.
+    ///
+    /// Returns a [`PlayerHandle`] to control playback and an [`EventReceiver`]
+    /// to observe transport events.
+    #[must_use]
+    pub fn spawn<T>(mut device: T, melody: Melody) -> (PlayerHandle, EventReceiver)
+    where
+        T: Beep + Send + 'static,
+    {
+        let (control_tx, control_rx) = mpsc::channel();
+        let (event_tx, event_rx) = mpsc::channel();
+
+        let worker = thread::spawn(move || run(&mut device, &melody, &control_rx, &event_tx));
+
+        (
+            PlayerHandle {
+                control: control_tx,
+                worker: Some(worker),
+            },
+            EventReceiver(event_rx),
+        )
+    }
+}
+
+/// What the worker should do next, derived from a control message.
+enum Decision {
+    Continue,
+    Stop,
+    Skip,
+    Seek(usize),
+}
+
+fn run<T: Beep>(device: &mut T, melody: &Melody, control: &Receiver<Control>, events: &Sender<Event>) {
+    let items = melody.events();
+    let mut index = 0;
+
+    while index < items.len() {
+        match poll_control(control) {
+            Decision::Stop => break,
+            Decision::Skip => {
+                index += 1;
+                continue;
+            }
+            Decision::Seek(target) => {
+                index = target.min(items.len());
+                continue;
+            }
+            Decision::Continue => {}
+        }
+
+        let decision = match &items[index] {
+            MelodyEvent::Note(note) => {
+                let _ = events.send(Event::NoteStarted {
+                    index,
+                    frequency: note.frequency(),
+                });
+                play_note(device, note, control)
+            }
+            MelodyEvent::Chord(chord, length) => {
+                let _ = events.send(Event::NoteStarted {
+                    index,
+                    frequency: chord.as_ref().first().copied().unwrap_or(0),
+                });
+                play_chord(device, chord, *length, control)
+            }
+        };
+
+        if matches!(decision, Decision::Stop) {
+            break;
+        }
+
+        let _ = events.send(Event::NoteFinished);
+
+        index = match decision {
+            Decision::Skip | Decision::Continue => index + 1,
+            Decision::Seek(target) => target.min(items.len()),
+            Decision::Stop => unreachable!("handled above"),
+        };
+    }
+
+    let _ = device.beep(0);
+    let _ = events.send(Event::MelodyFinished);
+}
+
+/// Plays a single note, including its repeats, checking for control messages
+/// between each repeat.
+fn play_note<T: Beep>(device: &mut T, note: &Note, control: &Receiver<Control>) -> Decision {
+    if note.repeats() == 0 {
+        return Decision::Continue;
+    }
+
+    if sound(device, note).is_err() {
+        return Decision::Stop;
+    }
+
+    for _ in 1..note.repeats() {
+        match poll_control(control) {
+            Decision::Continue => {}
+            decision => return decision,
+        }
+
+        sleep(note.delay());
+
+        if sound(device, note).is_err() {
+            return Decision::Stop;
+        }
+    }
+
+    Decision::Continue
+}
+
+fn sound<T: Beep>(device: &mut T, note: &Note) -> std::io::Result<()> {
+    device.beep(note.frequency())?;
+    sleep(note.length());
+    device.beep(0)
+}
+
+/// Plays a chord by round-robining between its frequencies for `length`,
+/// checking for control messages between each slice.
+fn play_chord<T: Beep>(device: &mut T, chord: &Chord, length: Duration, control: &Receiver<Control>) -> Decision {
+    let frequencies = chord.as_ref();
+
+    if frequencies.is_empty() || length.is_zero() {
+        return Decision::Continue;
+    }
+
+    let slice = chord::SLICE.min(length);
+    let mut elapsed = Duration::ZERO;
+    let mut step = 0;
+
+    while elapsed < length {
+        match poll_control(control) {
+            Decision::Continue => {}
+            decision => return decision,
+        }
+
+        let step_len = slice.min(length - elapsed);
+
+        if device.beep(frequencies[step % frequencies.len()]).is_err() {
+            return Decision::Stop;
+        }
+
+        sleep(step_len);
+        elapsed += step_len;
+        step += 1;
+    }
+
+    if device.beep(0).is_err() {
+        return Decision::Stop;
+    }
+
+    Decision::Continue
+}
+
+fn poll_control(control: &Receiver<Control>) -> Decision {
+    match control.try_recv() {
+        Ok(Control::Stop) => Decision::Stop,
+        Ok(Control::Skip) => Decision::Skip,
+        Ok(Control::Seek(index)) => Decision::Seek(index),
+        Ok(Control::Pause) => wait_for_resume(control),
+        Ok(Control::Resume) | Err(_) => Decision::Continue,
+    }
+}
+
+/// Blocks until the player is told to resume, stop, skip or seek.
+fn wait_for_resume(control: &Receiver<Control>) -> Decision {
+    loop {
+        match control.recv() {
+            Ok(Control::Resume) => return Decision::Continue,
+            Ok(Control::Stop) | Err(_) => return Decision::Stop,
+            Ok(Control::Skip) => return Decision::Skip,
+            Ok(Control::Seek(index)) => return Decision::Seek(index),
+            Ok(Control::Pause) => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A [`Beep`] device that records every frequency it was asked to play
+    /// and, once a given call count is reached, feeds a control message back
+    /// into the channel — simulating a real device hitting that point in
+    /// playback at the moment the worker checks for control messages.
+    struct FakeDevice {
+        calls: Vec<u16>,
+        inject: Option<(usize, Control)>,
+        control: Sender<Control>,
+    }
+
+    impl FakeDevice {
+        fn new(control: Sender<Control>) -> Self {
+            Self {
+                calls: Vec::new(),
+                inject: None,
+                control,
+            }
+        }
+
+        fn injecting(control: Sender<Control>, at_call: usize, message: Control) -> Self {
+            Self {
+                calls: Vec::new(),
+                inject: Some((at_call, message)),
+                control,
+            }
+        }
+    }
+
+    impl Beep for FakeDevice {
+        fn beep(&mut self, hertz: u16) -> std::io::Result<()> {
+            self.calls.push(hertz);
+
+            if let Some((at_call, message)) = self.inject {
+                if self.calls.len() == at_call {
+                    let _ = self.control.send(message);
+                }
+            }
+
+            Ok(())
+        }
+    }
+
+    fn note(frequency: u16, repeats: u16) -> Note {
+        Note::new(frequency, Duration::ZERO, repeats, Duration::ZERO)
+    }
+
+    fn drain(events: &Receiver<Event>) -> Vec<Event> {
+        events.try_iter().collect()
+    }
+
+    #[test]
+    fn plays_every_note_and_finishes() {
+        let melody = Melody::from_events(vec![
+            MelodyEvent::Note(note(100, 1)),
+            MelodyEvent::Note(note(200, 1)),
+        ]);
+        let (control_tx, control_rx) = mpsc::channel();
+        let (event_tx, event_rx) = mpsc::channel();
+        let mut device = FakeDevice::new(control_tx);
+
+        run(&mut device, &melody, &control_rx, &event_tx);
+
+        assert_eq!(device.calls, vec![100, 0, 200, 0, 0]);
+        assert_eq!(
+            drain(&event_rx),
+            vec![
+                Event::NoteStarted { index: 0, frequency: 100 },
+                Event::NoteFinished,
+                Event::NoteStarted { index: 1, frequency: 200 },
+                Event::NoteFinished,
+                Event::MelodyFinished,
+            ]
+        );
+    }
+
+    #[test]
+    fn pause_then_resume_continues_playback() {
+        let melody = Melody::from_events(vec![MelodyEvent::Note(note(100, 1))]);
+        let (control_tx, control_rx) = mpsc::channel();
+        let (event_tx, event_rx) = mpsc::channel();
+        control_tx.send(Control::Pause).unwrap();
+        control_tx.send(Control::Resume).unwrap();
+        let mut device = FakeDevice::new(control_tx);
+
+        run(&mut device, &melody, &control_rx, &event_tx);
+
+        assert_eq!(device.calls, vec![100, 0, 0]);
+        assert_eq!(
+            drain(&event_rx),
+            vec![
+                Event::NoteStarted { index: 0, frequency: 100 },
+                Event::NoteFinished,
+                Event::MelodyFinished,
+            ]
+        );
+    }
+
+    #[test]
+    fn pause_then_stop_stops_without_playing() {
+        let melody = Melody::from_events(vec![MelodyEvent::Note(note(100, 1))]);
+        let (control_tx, control_rx) = mpsc::channel();
+        let (event_tx, event_rx) = mpsc::channel();
+        control_tx.send(Control::Pause).unwrap();
+        control_tx.send(Control::Stop).unwrap();
+        let mut device = FakeDevice::new(control_tx);
+
+        run(&mut device, &melody, &control_rx, &event_tx);
+
+        assert_eq!(device.calls, vec![0]);
+        assert_eq!(drain(&event_rx), vec![Event::MelodyFinished]);
+    }
+
+    #[test]
+    fn stop_mid_repeats_stops_before_exhausting_them() {
+        let melody = Melody::from_events(vec![MelodyEvent::Note(note(100, 5))]);
+        let (control_tx, control_rx) = mpsc::channel();
+        let (event_tx, event_rx) = mpsc::channel();
+        // Stop arrives once the first repeat has sounded (calls 1 and 2).
+        let mut device = FakeDevice::injecting(control_tx, 2, Control::Stop);
+
+        run(&mut device, &melody, &control_rx, &event_tx);
+
+        assert_eq!(device.calls, vec![100, 0, 0]);
+        assert_eq!(
+            drain(&event_rx),
+            vec![Event::NoteStarted { index: 0, frequency: 100 }, Event::MelodyFinished]
+        );
+    }
+
+    #[test]
+    fn skip_moves_to_next_event_immediately() {
+        let melody = Melody::from_events(vec![
+            MelodyEvent::Note(note(100, 3)),
+            MelodyEvent::Note(note(200, 1)),
+        ]);
+        let (control_tx, control_rx) = mpsc::channel();
+        let (event_tx, event_rx) = mpsc::channel();
+        // Skip arrives once the first repeat of the first note has sounded.
+        let mut device = FakeDevice::injecting(control_tx, 2, Control::Skip);
+
+        run(&mut device, &melody, &control_rx, &event_tx);
+
+        assert_eq!(device.calls, vec![100, 0, 200, 0, 0]);
+        assert_eq!(
+            drain(&event_rx),
+            vec![
+                Event::NoteStarted { index: 0, frequency: 100 },
+                Event::NoteFinished,
+                Event::NoteStarted { index: 1, frequency: 200 },
+                Event::NoteFinished,
+                Event::MelodyFinished,
+            ]
+        );
+    }
+
+    #[test]
+    fn seek_past_end_finishes_without_playing() {
+        let melody = Melody::from_events(vec![
+            MelodyEvent::Note(note(100, 1)),
+            MelodyEvent::Note(note(200, 1)),
+        ]);
+        let (control_tx, control_rx) = mpsc::channel();
+        let (event_tx, event_rx) = mpsc::channel();
+        control_tx.send(Control::Seek(10)).unwrap();
+        let mut device = FakeDevice::new(control_tx);
+
+        run(&mut device, &melody, &control_rx, &event_tx);
+
+        assert_eq!(device.calls, vec![0]);
+        assert_eq!(drain(&event_rx), vec![Event::MelodyFinished]);
+    }
+
+    #[test]
+    fn seek_mid_melody_jumps_to_index() {
+        let melody = Melody::from_events(vec![
+            MelodyEvent::Note(note(100, 1)),
+            MelodyEvent::Note(note(200, 1)),
+            MelodyEvent::Note(note(300, 1)),
+        ]);
+        let (control_tx, control_rx) = mpsc::channel();
+        let (event_tx, event_rx) = mpsc::channel();
+        control_tx.send(Control::Seek(2)).unwrap();
+        let mut device = FakeDevice::new(control_tx);
+
+        run(&mut device, &melody, &control_rx, &event_tx);
+
+        assert_eq!(device.calls, vec![300, 0, 0]);
+        assert_eq!(
+            drain(&event_rx),
+            vec![
+                Event::NoteStarted { index: 2, frequency: 300 },
+                Event::NoteFinished,
+                Event::MelodyFinished,
+            ]
+        );
+    }
+
+    #[test]
+    fn chord_round_robins_through_frequencies() {
+        let chord = Chord::from(vec![100, 200, 300]);
+        let length = chord::SLICE * 3;
+        let (control_tx, control_rx) = mpsc::channel();
+        let mut device = FakeDevice::new(control_tx);
+
+        let decision = play_chord(&mut device, &chord, length, &control_rx);
+
+        assert!(matches!(decision, Decision::Continue));
+        assert_eq!(device.calls, vec![100, 200, 300, 0]);
+    }
+
+    #[test]
+    fn chord_stops_mid_arpeggio_on_control_message() {
+        let chord = Chord::from(vec![100, 200, 300]);
+        let length = chord::SLICE * 3;
+        let (control_tx, control_rx) = mpsc::channel();
+        // Stop arrives after the first slice has sounded.
+        let mut device = FakeDevice::injecting(control_tx, 1, Control::Stop);
+
+        let decision = play_chord(&mut device, &chord, length, &control_rx);
+
+        assert!(matches!(decision, Decision::Stop));
+        assert_eq!(device.calls, vec![100]);
+    }
+}
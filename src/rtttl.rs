@@ -0,0 +1,202 @@
+//! Ring Tone Text Transfer Language (RTTTL) (de)serialization used by
+//! [`crate::Melody::from_rtttl`] and [`crate::Melody::to_rtttl`].
+
+use std::time::Duration;
+
+use crate::melody::MelodyEvent;
+use crate::note::{frequency_from_midi, midi_from_name};
+use crate::{Note, ParseError};
+
+/// The classic RTTTL default tempo, used when saving a [`crate::Melody`]
+/// that has no tempo of its own.
+pub(crate) const DEFAULT_BPM: f64 = 63.0;
+
+const DURATIONS: [u32; 6] = [1, 2, 4, 8, 16, 32];
+const NOTE_NAMES: [&str; 12] = ["c", "c#", "d", "d#", "e", "f", "f#", "g", "g#", "a", "a#", "b"];
+
+/// Parses an RTTTL string into a sequence of [`MelodyEvent`]s.
+pub(crate) fn parse(input: &str) -> Result<Vec<MelodyEvent>, ParseError> {
+    let mut parts = input.splitn(3, ':');
+    parts.next().ok_or(ParseError::InvalidNoteName)?; // name, unused
+    let settings = parts.next().ok_or(ParseError::InvalidNoteName)?;
+    let notes = parts.next().ok_or(ParseError::InvalidNoteName)?;
+
+    let mut default_duration = 4;
+    let mut default_octave = 5;
+    let mut bpm = DEFAULT_BPM;
+
+    for setting in settings.split(',').map(str::trim) {
+        if let Some(value) = setting.strip_prefix("d=") {
+            default_duration = value.parse().map_err(|_| ParseError::InvalidNoteName)?;
+            if default_duration == 0 {
+                return Err(ParseError::InvalidDuration);
+            }
+        } else if let Some(value) = setting.strip_prefix("o=") {
+            default_octave = value.parse().map_err(|_| ParseError::InvalidNoteName)?;
+        } else if let Some(value) = setting.strip_prefix("b=") {
+            bpm = value.parse().map_err(|_| ParseError::InvalidNoteName)?;
+            if !bpm.is_finite() || bpm <= 0.0 {
+                return Err(ParseError::InvalidTempo);
+            }
+        }
+    }
+
+    notes
+        .split(',')
+        .map(str::trim)
+        .filter(|token| !token.is_empty())
+        .map(|token| parse_note(token, default_duration, default_octave, bpm))
+        .collect()
+}
+
+/// Renders `events` as an RTTTL string with the given `name` and `bpm`.
+///
+/// Each note carries its own explicit duration and octave, so the header's
+/// `d=4,o=5` defaults are never relied upon and the mapping back from
+/// frequency/length is lossless up to rounding each length to the nearest
+/// standard duration and each frequency to the nearest semitone. RTTTL has
+/// no syntax for simultaneous notes, so a [`MelodyEvent::Chord`] is exported
+/// as a single note at its highest frequency (the top voice) and every
+/// other voice in the chord is intentionally dropped.
+pub(crate) fn format(events: &[MelodyEvent], name: &str, bpm: f64) -> String {
+    let notes = events
+        .iter()
+        .map(|event| {
+            let (frequency, length) = match event {
+                MelodyEvent::Note(note) => (note.frequency(), note.length()),
+                MelodyEvent::Chord(chord, length) => (chord.as_ref().iter().copied().max().unwrap_or(0), *length),
+            };
+            let beats = length.as_secs_f64() * bpm / 60.0;
+            format!("{}{}", duration_token(beats), note_name(frequency))
+        })
+        .collect::<Vec<_>>()
+        .join(",");
+
+    format!("{name}:d=4,o=5,b={}:{notes}", bpm.round())
+}
+
+fn parse_note(token: &str, default_duration: u32, default_octave: u8, bpm: f64) -> Result<MelodyEvent, ParseError> {
+    let mut chars = token.chars().peekable();
+
+    let duration = take_digits(&mut chars)
+        .map(|digits| digits.parse().map_err(|_| ParseError::InvalidNoteName))
+        .transpose()?
+        .unwrap_or(default_duration);
+
+    if duration == 0 {
+        return Err(ParseError::InvalidDuration);
+    }
+
+    let mut dotted = chars.next_if_eq(&'.').is_some();
+
+    let letter = chars.next().ok_or(ParseError::InvalidNoteName)?;
+
+    let frequency = if letter.eq_ignore_ascii_case(&'p') {
+        0
+    } else {
+        let sharp = chars.next_if_eq(&'#').is_some();
+        let octave = take_digits(&mut chars)
+            .map(|digits| digits.parse().map_err(|_| ParseError::InvalidNoteName))
+            .transpose()?
+            .unwrap_or(default_octave);
+        let name = format!(
+            "{}{}{octave}",
+            letter.to_ascii_uppercase(),
+            if sharp { "#" } else { "" }
+        );
+        frequency_from_midi(midi_from_name(&name)?)
+    };
+
+    dotted |= chars.next_if_eq(&'.').is_some();
+    let beats = 4.0 / f64::from(duration) * if dotted { 1.5 } else { 1.0 };
+    let length = Duration::from_secs_f64(60.0 / bpm * beats);
+
+    Ok(MelodyEvent::Note(Note::from((frequency, length))))
+}
+
+fn take_digits(chars: &mut std::iter::Peekable<std::str::Chars<'_>>) -> Option<String> {
+    let mut digits = String::new();
+
+    while let Some(&c) = chars.peek() {
+        if !c.is_ascii_digit() {
+            break;
+        }
+
+        digits.push(c);
+        chars.next();
+    }
+
+    (!digits.is_empty()).then_some(digits)
+}
+
+fn duration_token(beats: f64) -> String {
+    let mut best_denominator = DURATIONS[0];
+    let mut best_dotted = false;
+    let mut best_error = f64::MAX;
+
+    for &denominator in &DURATIONS {
+        let base = 4.0 / f64::from(denominator);
+
+        for (dotted, candidate) in [(false, base), (true, base * 1.5)] {
+            let error = (candidate - beats).abs();
+
+            if error < best_error {
+                best_denominator = denominator;
+                best_dotted = dotted;
+                best_error = error;
+            }
+        }
+    }
+
+    format!("{best_denominator}{}", if best_dotted { "." } else { "" })
+}
+
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn note_name(frequency: u16) -> String {
+    if frequency == 0 {
+        return "p".to_owned();
+    }
+
+    let key = (69.0 + 12.0 * (f64::from(frequency) / 440.0).log2()).round() as i32;
+    let semitone = key.rem_euclid(12) as usize;
+    let octave = key / 12 - 1;
+
+    format!("{}{octave}", NOTE_NAMES[semitone])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse;
+    use crate::ParseError;
+
+    #[test]
+    fn zero_tempo_is_invalid_tempo() {
+        assert!(matches!(parse("x:b=0:4a"), Err(ParseError::InvalidTempo)));
+    }
+
+    #[test]
+    fn negative_tempo_is_invalid_tempo() {
+        assert!(matches!(parse("x:b=-1:4a"), Err(ParseError::InvalidTempo)));
+    }
+
+    #[test]
+    fn zero_header_duration_is_invalid_duration() {
+        assert!(matches!(parse("x:d=0:a"), Err(ParseError::InvalidDuration)));
+    }
+
+    #[test]
+    fn zero_note_duration_is_invalid_duration() {
+        assert!(matches!(parse("x:d=4,o=5,b=100:0a"), Err(ParseError::InvalidDuration)));
+    }
+
+    #[test]
+    fn unknown_note_letter_is_invalid_note_name() {
+        assert!(matches!(parse("x:d=4,o=5,b=100:4h"), Err(ParseError::InvalidNoteName)));
+    }
+
+    #[test]
+    fn parses_notes_and_pauses() {
+        let events = parse("x:d=4,o=5,b=100:4a,8p,2c#6").expect("valid RTTTL should parse");
+        assert_eq!(events.len(), 3);
+    }
+}
@@ -0,0 +1,231 @@
+//! Minimal Standard MIDI File (SMF) reader used by [`crate::Melody::from_smf`].
+
+use crate::ParseError;
+
+/// Parsed `MThd` header.
+pub(crate) struct Header {
+    pub(crate) division: u16,
+}
+
+/// A channel-voice or tempo event with its absolute tick position within its track.
+pub(crate) struct TimedEvent {
+    pub(crate) tick: u64,
+    pub(crate) event: TrackEvent,
+}
+
+pub(crate) enum TrackEvent {
+    NoteOn { key: u8 },
+    NoteOff { key: u8 },
+    Tempo(u32),
+}
+
+/// A parsed `<id><length><payload>` chunk plus whatever bytes follow it.
+struct Chunk<'a> {
+    id: [u8; 4],
+    payload: &'a [u8],
+    rest: &'a [u8],
+}
+
+/// Parses `bytes` as an SMF, returning the header and every track's events
+/// merged into a single list sorted by absolute tick.
+pub(crate) fn parse(bytes: &[u8]) -> Result<(Header, Vec<TimedEvent>), ParseError> {
+    let (header, mut rest) = parse_header(bytes)?;
+    let mut events = Vec::new();
+
+    while !rest.is_empty() {
+        let chunk = read_chunk(rest)?;
+        rest = chunk.rest;
+
+        if &chunk.id == b"MTrk" {
+            events.extend(parse_track(chunk.payload)?);
+        }
+    }
+
+    events.sort_by_key(|timed_event| timed_event.tick);
+    Ok((header, events))
+}
+
+fn parse_header(bytes: &[u8]) -> Result<(Header, &[u8]), ParseError> {
+    let chunk = read_chunk(bytes)?;
+
+    if &chunk.id != b"MThd" || chunk.payload.len() < 6 {
+        return Err(ParseError::InvalidChunkHeader);
+    }
+
+    let division = u16::from_be_bytes([chunk.payload[4], chunk.payload[5]]);
+    Ok((Header { division }, chunk.rest))
+}
+
+/// Reads a `<4-byte id><4-byte big-endian length><payload>` chunk, returning
+/// the id, the payload and whatever follows it.
+fn read_chunk(bytes: &[u8]) -> Result<Chunk<'_>, ParseError> {
+    if bytes.len() < 8 {
+        return Err(ParseError::UnexpectedEof);
+    }
+
+    let id = [bytes[0], bytes[1], bytes[2], bytes[3]];
+    let len = u32::from_be_bytes([bytes[4], bytes[5], bytes[6], bytes[7]]) as usize;
+    let body = &bytes[8..];
+
+    if body.len() < len {
+        return Err(ParseError::TruncatedChunk);
+    }
+
+    Ok(Chunk {
+        id,
+        payload: &body[..len],
+        rest: &body[len..],
+    })
+}
+
+/// Reads a variable-length quantity, returning its value and the number of bytes consumed.
+fn read_vlq(bytes: &[u8]) -> Result<(u32, usize), ParseError> {
+    let mut value: u32 = 0;
+
+    for (consumed, &byte) in bytes.iter().enumerate() {
+        value = (value << 7) | u32::from(byte & 0x7F);
+
+        if byte & 0x80 == 0 {
+            return Ok((value, consumed + 1));
+        }
+    }
+
+    Err(ParseError::UnexpectedEof)
+}
+
+fn parse_track(data: &[u8]) -> Result<Vec<TimedEvent>, ParseError> {
+    let mut events = Vec::new();
+    let mut pos = 0;
+    let mut tick: u64 = 0;
+    let mut running_status: Option<u8> = None;
+
+    while pos < data.len() {
+        let (delta, consumed) = read_vlq(&data[pos..])?;
+        pos += consumed;
+        tick += u64::from(delta);
+
+        let byte = *data.get(pos).ok_or(ParseError::UnexpectedEof)?;
+        let status = if byte >= 0x80 {
+            pos += 1;
+            byte
+        } else {
+            running_status.ok_or(ParseError::InvalidEvent)?
+        };
+
+        match status {
+            0x80..=0x8F => {
+                let key = read_byte(data, &mut pos)?;
+                let _velocity = read_byte(data, &mut pos)?;
+                running_status = Some(status);
+                events.push(TimedEvent {
+                    tick,
+                    event: TrackEvent::NoteOff { key },
+                });
+            }
+            0x90..=0x9F => {
+                let key = read_byte(data, &mut pos)?;
+                let velocity = read_byte(data, &mut pos)?;
+                running_status = Some(status);
+                let event = if velocity == 0 {
+                    TrackEvent::NoteOff { key }
+                } else {
+                    TrackEvent::NoteOn { key }
+                };
+                events.push(TimedEvent { tick, event });
+            }
+            0xA0..=0xBF | 0xE0..=0xEF => {
+                read_byte(data, &mut pos)?;
+                read_byte(data, &mut pos)?;
+                running_status = Some(status);
+            }
+            0xC0..=0xDF => {
+                read_byte(data, &mut pos)?;
+                running_status = Some(status);
+            }
+            0xFF => {
+                let meta_type = read_byte(data, &mut pos)?;
+                let (len, consumed) = read_vlq(&data[pos..])?;
+                pos += consumed;
+                let len = len as usize;
+                let payload = data.get(pos..pos + len).ok_or(ParseError::UnexpectedEof)?;
+                pos += len;
+
+                if meta_type == 0x51 && payload.len() == 3 {
+                    let usec_per_quarter =
+                        u32::from(payload[0]) << 16 | u32::from(payload[1]) << 8 | u32::from(payload[2]);
+                    events.push(TimedEvent {
+                        tick,
+                        event: TrackEvent::Tempo(usec_per_quarter),
+                    });
+                }
+            }
+            0xF0 | 0xF7 => {
+                let (len, consumed) = read_vlq(&data[pos..])?;
+                pos += consumed;
+                pos += len as usize;
+
+                if pos > data.len() {
+                    return Err(ParseError::UnexpectedEof);
+                }
+            }
+            _ => return Err(ParseError::InvalidEvent),
+        }
+    }
+
+    Ok(events)
+}
+
+fn read_byte(data: &[u8], pos: &mut usize) -> Result<u8, ParseError> {
+    let byte = *data.get(*pos).ok_or(ParseError::UnexpectedEof)?;
+    *pos += 1;
+    Ok(byte)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse;
+    use crate::ParseError;
+
+    #[test]
+    fn empty_input_is_unexpected_eof() {
+        assert!(matches!(parse(&[]), Err(ParseError::UnexpectedEof)));
+    }
+
+    #[test]
+    fn wrong_header_magic_is_invalid_chunk_header() {
+        let bytes = b"Xxxx\x00\x00\x00\x06\x00\x00\x00\x01\x00\x60";
+        assert!(matches!(parse(bytes), Err(ParseError::InvalidChunkHeader)));
+    }
+
+    #[test]
+    fn chunk_length_past_end_of_input_is_truncated() {
+        // MThd chunk whose declared length (6) reaches past the 2 payload
+        // bytes actually present.
+        let bytes = b"MThd\x00\x00\x00\x06\x00\x00";
+        assert!(matches!(parse(bytes), Err(ParseError::TruncatedChunk)));
+    }
+
+    #[test]
+    fn track_with_dangling_running_status_is_invalid_event() {
+        // Valid MThd header followed by an MTrk chunk whose first event byte
+        // is a data byte (< 0x80) with no running status yet established.
+        let mut bytes = b"MThd\x00\x00\x00\x06\x00\x00\x00\x01\x00\x60".to_vec();
+        bytes.extend_from_slice(b"MTrk\x00\x00\x00\x02\x00\x10");
+        assert!(matches!(parse(&bytes), Err(ParseError::InvalidEvent)));
+    }
+
+    #[test]
+    fn parses_a_single_note_on_and_off() {
+        let mut bytes = b"MThd\x00\x00\x00\x06\x00\x00\x00\x01\x00\x60".to_vec();
+        // delta 0, Note On channel 0, key 60, velocity 64;
+        // delta 96, Note Off channel 0, key 60, velocity 0.
+        let track: &[u8] = &[0x00, 0x90, 0x3C, 0x40, 0x60, 0x80, 0x3C, 0x00];
+        bytes.extend_from_slice(b"MTrk");
+        bytes.extend_from_slice(&(track.len() as u32).to_be_bytes());
+        bytes.extend_from_slice(track);
+
+        let (header, events) = parse(&bytes).expect("valid SMF should parse");
+        assert_eq!(header.division, 0x60);
+        assert_eq!(events.len(), 2);
+    }
+}